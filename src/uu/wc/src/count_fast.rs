@@ -0,0 +1,153 @@
+//  * This file is part of the uutils coreutils package.
+//  *
+//  * (c) Boden Garman <bpgarman@gmail.com>
+//  *
+//  * For the full copyright and license information, please view the LICENSE
+//  * file that was distributed with this source code.
+
+use crate::countable::WordCountable;
+use crate::word_count::WordCount;
+use memchr::Memchr;
+use std::io::{self, ErrorKind, Read};
+
+/// The size of the buffer used for reading the input. Large enough that a
+/// handful of reads amortizes the read syscall over a sizeable chunk for
+/// `memchr` to scan.
+const BUF_SIZE: usize = 256 * 1024;
+
+/// Count the `\n` bytes in `buf`.
+///
+/// `memchr::Memchr` scans in word-sized (and, where available, SIMD)
+/// strides internally, which is the vectorized win we want here without
+/// hand-rolling a counting-correct bit trick: a naive SWAR zero-byte test
+/// detects *a* match per word but is not exact once borrows from one
+/// matched byte can propagate into its neighbor, so it cannot be used to
+/// tally exact counts.
+fn count_newlines(buf: &[u8]) -> usize {
+    Memchr::new(b'\n', buf).count()
+}
+
+/// Read from this file and return the number of lines and bytes, counting
+/// neither words nor chars nor the max line length.
+///
+/// This is the fast path taken when `-l` is requested, alone or with `-c`,
+/// and no counter that needs decoding (words, chars, max line length) is,
+/// since it avoids the UTF-8 decoding step that the general counting loop
+/// needs.
+pub(crate) fn count_bytes_and_lines_fast<T: WordCountable>(
+    reader: &mut T,
+) -> (WordCount, Option<io::Error>) {
+    let mut total_lines = 0;
+    let mut total_bytes = 0;
+    let mut buf = [0_u8; BUF_SIZE];
+
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => {
+                return (
+                    WordCount {
+                        bytes: total_bytes,
+                        lines: total_lines,
+                        ..WordCount::default()
+                    },
+                    None,
+                )
+            }
+            Ok(n) => {
+                total_lines += count_newlines(&buf[..n]);
+                total_bytes += n;
+            }
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => {
+                return (
+                    WordCount {
+                        bytes: total_bytes,
+                        lines: total_lines,
+                        ..WordCount::default()
+                    },
+                    Some(e),
+                )
+            }
+        }
+    }
+}
+
+/// Read from this file and return only the number of bytes.
+pub(crate) fn count_bytes_fast<T: WordCountable>(reader: &mut T) -> (usize, Option<io::Error>) {
+    let mut total_bytes = 0;
+    let mut buf = [0_u8; BUF_SIZE];
+
+    loop {
+        match reader.read(&mut buf) {
+            Ok(0) => return (total_bytes, None),
+            Ok(n) => total_bytes += n,
+            Err(ref e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return (total_bytes, Some(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::count_newlines;
+
+    /// A deliberately naive scalar oracle to check `count_newlines`
+    /// against, so a regression in the vectorized path shows up here
+    /// rather than as a silently wrong `wc -l`.
+    fn count_newlines_scalar(buf: &[u8]) -> usize {
+        buf.iter().filter(|&&b| b == b'\n').count()
+    }
+
+    #[test]
+    fn counts_simple_lines() {
+        let buf = b"a\nb\nc\nd\ne\nf\ng\n";
+        assert_eq!(count_newlines(buf), 7);
+        assert_eq!(count_newlines(buf), count_newlines_scalar(buf));
+    }
+
+    #[test]
+    fn counts_consecutive_newlines() {
+        let buf = b"\n".repeat(8);
+        assert_eq!(count_newlines(&buf), 8);
+    }
+
+    #[test]
+    fn does_not_overcount_on_borrow_propagation() {
+        // A `\n` (0x0a) followed by `\x0b` bytes is exactly the case where
+        // a subtraction-based zero-byte test can borrow into the next
+        // byte and false-positive; this must count exactly one newline.
+        let mut buf = vec![b'\n'];
+        buf.extend(std::iter::repeat(0x0b_u8).take(7));
+        assert_eq!(count_newlines(&buf), 1);
+    }
+
+    #[test]
+    fn matches_scalar_oracle_across_buffer_boundaries() {
+        // Exercise lengths before, at, and after an 8-byte word boundary,
+        // and well past a single vector stride, so a bug confined to the
+        // trailing remainder or to the main scanning loop would show up.
+        for len in [0, 1, 7, 8, 9, 63, 64, 65, 10_000] {
+            let mut buf = vec![0_u8; len];
+            // Every third byte is a newline, interleaved with
+            // non-newline bytes to guard against a test that only
+            // happens to pass on all-`\n` input.
+            for (i, b) in buf.iter_mut().enumerate() {
+                *b = if i % 3 == 0 { b'\n' } else { b'x' };
+            }
+            assert_eq!(
+                count_newlines(&buf),
+                count_newlines_scalar(&buf),
+                "mismatch for len = {len}"
+            );
+        }
+    }
+
+    #[test]
+    fn counts_many_newlines_in_a_large_buffer() {
+        let mut buf = vec![b'x'; 1000];
+        for i in (0..1000).step_by(10) {
+            buf[i] = b'\n';
+        }
+        assert_eq!(count_newlines(&buf), 100);
+    }
+}
@@ -0,0 +1,45 @@
+//  * This file is part of the uutils coreutils package.
+//  *
+//  * (c) Boden Garman <bpgarman@gmail.com>
+//  *
+//  * For the full copyright and license information, please view the LICENSE
+//  * file that was distributed with this source code.
+
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+
+/// A trait that unifies the different kinds of readers `wc` counts over.
+///
+/// `StdinLock` already owns an internal buffer shared across locks, while a
+/// plain `File` needs to be wrapped in a `BufReader` to get one, and a
+/// memory-mapped `&[u8]` is already its own buffer. This trait lets the
+/// counting code stay generic over all three without caring which is which.
+pub trait WordCountable: Read {
+    type Buffered: io::BufRead;
+
+    fn buffered(self) -> Self::Buffered;
+}
+
+impl<'a> WordCountable for io::StdinLock<'a> {
+    type Buffered = Self;
+
+    fn buffered(self) -> Self {
+        self
+    }
+}
+
+impl WordCountable for File {
+    type Buffered = BufReader<Self>;
+
+    fn buffered(self) -> Self::Buffered {
+        BufReader::new(self)
+    }
+}
+
+impl<'a> WordCountable for &'a [u8] {
+    type Buffered = Self;
+
+    fn buffered(self) -> Self {
+        self
+    }
+}
@@ -0,0 +1,62 @@
+//  * This file is part of the uutils coreutils package.
+//  *
+//  * (c) Boden Garman <bpgarman@gmail.com>
+//  *
+//  * For the full copyright and license information, please view the LICENSE
+//  * file that was distributed with this source code.
+
+use memmap2::Mmap;
+use std::fs::{self, File};
+use std::path::Path;
+
+/// Only take the mmap path for regular files at least this large; below
+/// this, the overhead of mapping the file outweighs what it saves over a
+/// couple of buffered reads.
+const MMAP_THRESHOLD: u64 = 16 * 1024 * 1024;
+
+/// Try to memory-map `path` for the counting hot path.
+///
+/// Returns `None` for anything that isn't a regular file of at least
+/// [`MMAP_THRESHOLD`] bytes, or if the mapping itself fails (e.g. `path`
+/// is a pipe, a `/proc` file, or the platform doesn't support `mmap`).
+/// Callers are expected to fall back to the regular streaming reader in
+/// that case, so behavior and error reporting stay unchanged either way.
+pub(crate) fn try_mmap(path: &Path) -> Option<Mmap> {
+    let metadata = fs::metadata(path).ok()?;
+    if !metadata.is_file() || metadata.len() < MMAP_THRESHOLD {
+        return None;
+    }
+
+    let file = File::open(path).ok()?;
+    // SAFETY: the mapping is only read through an immutable slice for the
+    // lifetime of this counting pass; if another process truncates the
+    // file concurrently that's the same caveat every mmap-based reader
+    // accepts.
+    unsafe { Mmap::map(&file).ok() }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::try_mmap;
+    use std::path::{Path, PathBuf};
+
+    #[test]
+    fn falls_back_for_a_missing_path() {
+        assert!(try_mmap(Path::new("/no/such/file/wc-mmap-input-test")).is_none());
+    }
+
+    #[test]
+    fn falls_back_for_a_file_below_the_threshold() {
+        let path = write_temp_file("wc-mmap-below-threshold", b"well below the mmap threshold\n");
+        assert!(try_mmap(&path).is_none());
+        let _ = std::fs::remove_file(&path);
+    }
+
+    /// Write `contents` to a fresh temp file named `name` and return its path.
+    fn write_temp_file(name: &str, contents: &[u8]) -> PathBuf {
+        let mut path = std::env::temp_dir();
+        path.push(format!("{name}-{}", std::process::id()));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+}
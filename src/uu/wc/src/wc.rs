@@ -10,9 +10,11 @@ extern crate uucore;
 
 mod count_fast;
 mod countable;
+mod mmap_input;
 mod word_count;
 use count_fast::{count_bytes_and_lines_fast, count_bytes_fast};
 use countable::WordCountable;
+use mmap_input::try_mmap;
 use unicode_width::UnicodeWidthChar;
 use utf8::{BufReadDecoder, BufReadDecoderError};
 use uucore::format_usage;
@@ -27,6 +29,8 @@ use std::fmt::Display;
 use std::fs::{self, File};
 use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc};
+use std::thread;
 
 use uucore::display::{Quotable, Quoted};
 use uucore::error::{UError, UResult, USimpleError};
@@ -34,22 +38,120 @@ use uucore::error::{UError, UResult, USimpleError};
 /// The minimum character width for formatting counts when reading from stdin.
 const MINIMUM_WIDTH: usize = 7;
 
+/// Up to this many path inputs, the fixed cost of spinning up a thread
+/// pool outweighs any gain from counting files in parallel.
+const PARALLEL_FILE_THRESHOLD: usize = 4;
+
+/// Controls when the total line is printed, mirroring GNU `wc --total`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TotalWhen {
+    /// Print the total only when more than one input was given.
+    Auto,
+    /// Always print the total, even for a single input.
+    Always,
+    /// Print only the total, suppressing every per-file line.
+    Only,
+    /// Never print the total.
+    Never,
+}
+
+impl Default for TotalWhen {
+    fn default() -> Self {
+        Self::Auto
+    }
+}
+
+impl From<&str> for TotalWhen {
+    fn from(s: &str) -> Self {
+        match s {
+            "always" => Self::Always,
+            "only" => Self::Only,
+            "never" => Self::Never,
+            _ => Self::Auto,
+        }
+    }
+}
+
+impl TotalWhen {
+    /// Whether the total line should be printed for `num_inputs` inputs.
+    fn is_total_row_visible(self, num_inputs: usize) -> bool {
+        match self {
+            Self::Always | Self::Only => true,
+            Self::Auto => num_inputs > 1,
+            Self::Never => false,
+        }
+    }
+
+    /// Whether the per-file rows should be printed at all.
+    fn are_rows_visible(self) -> bool {
+        self != Self::Only
+    }
+}
+
+/// The shape `print_stats` serializes a [`TitledWordCount`] into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    /// The traditional space-padded columns.
+    Default,
+    /// One JSON object per input, for scripting.
+    Json,
+    /// One CSV row per input, for scripting.
+    Csv,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
+impl From<&str> for OutputFormat {
+    fn from(s: &str) -> Self {
+        match s {
+            "json" => Self::Json,
+            "csv" => Self::Csv,
+            _ => Self::Default,
+        }
+    }
+}
+
+#[derive(Clone)]
 struct Settings {
     show_bytes: bool,
     show_chars: bool,
     show_lines: bool,
     show_words: bool,
     show_max_line_length: bool,
+    total_when: TotalWhen,
+    /// Number of worker threads requested via `--jobs`, or `None` to let
+    /// `wc` decide automatically based on the number of path inputs.
+    jobs: Option<usize>,
+    format: OutputFormat,
 }
 
 impl Settings {
-    fn new(matches: &ArgMatches) -> Self {
+    fn new(matches: &ArgMatches) -> UResult<Self> {
+        let total_when = matches
+            .value_of(options::TOTAL)
+            .map(TotalWhen::from)
+            .unwrap_or_default();
+
+        let jobs = parse_jobs(matches)?;
+
+        let format = matches
+            .value_of(options::FORMAT)
+            .map(OutputFormat::from)
+            .unwrap_or_default();
+
         let settings = Self {
             show_bytes: matches.is_present(options::BYTES),
             show_chars: matches.is_present(options::CHAR),
             show_lines: matches.is_present(options::LINES),
             show_words: matches.is_present(options::WORDS),
             show_max_line_length: matches.is_present(options::MAX_LINE_LENGTH),
+            total_when,
+            jobs,
+            format,
         };
 
         if settings.show_bytes
@@ -58,16 +160,19 @@ impl Settings {
             || settings.show_words
             || settings.show_max_line_length
         {
-            return settings;
+            return Ok(settings);
         }
 
-        Self {
+        Ok(Self {
             show_bytes: true,
             show_chars: false,
             show_lines: true,
             show_words: true,
             show_max_line_length: false,
-        }
+            total_when,
+            jobs,
+            format,
+        })
     }
 
     fn number_enabled(&self) -> u32 {
@@ -89,8 +194,11 @@ pub mod options {
     pub static BYTES: &str = "bytes";
     pub static CHAR: &str = "chars";
     pub static FILES0_FROM: &str = "files0-from";
+    pub static FORMAT: &str = "format";
+    pub static JOBS: &str = "jobs";
     pub static LINES: &str = "lines";
     pub static MAX_LINE_LENGTH: &str = "max-line-length";
+    pub static TOTAL: &str = "total";
     pub static WORDS: &str = "words";
 }
 
@@ -146,17 +254,20 @@ impl Input {
 enum WcError {
     FilesDisabled(String),
     StdinReprNotAllowed(String),
+    InvalidJobs(String),
 }
 
 impl UError for WcError {
     fn code(&self) -> i32 {
         match self {
-            WcError::FilesDisabled(_) | WcError::StdinReprNotAllowed(_) => 1,
+            WcError::FilesDisabled(_)
+            | WcError::StdinReprNotAllowed(_)
+            | WcError::InvalidJobs(_) => 1,
         }
     }
 
     fn usage(&self) -> bool {
-        matches!(self, WcError::FilesDisabled(_))
+        matches!(self, WcError::FilesDisabled(_) | WcError::InvalidJobs(_))
     }
 }
 
@@ -165,20 +276,38 @@ impl Error for WcError {}
 impl Display for WcError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            WcError::FilesDisabled(message) | WcError::StdinReprNotAllowed(message) => {
+            WcError::FilesDisabled(message)
+            | WcError::StdinReprNotAllowed(message)
+            | WcError::InvalidJobs(message) => {
                 write!(f, "{}", message)
             }
         }
     }
 }
 
+/// Parse `--jobs`, rejecting anything that isn't a positive integer
+/// instead of silently falling back to a default.
+fn parse_jobs(matches: &ArgMatches) -> UResult<Option<usize>> {
+    match matches.value_of(options::JOBS) {
+        None => Ok(None),
+        Some(value) => match value.parse::<usize>() {
+            Ok(n) if n > 0 => Ok(Some(n)),
+            _ => Err(WcError::InvalidJobs(format!(
+                "invalid --jobs value '{}': expected a positive integer",
+                value
+            ))
+            .into()),
+        },
+    }
+}
+
 #[uucore::main]
 pub fn uumain(args: impl uucore::Args) -> UResult<()> {
     let matches = uu_app().get_matches_from(args);
 
     let inputs = inputs(&matches)?;
 
-    let settings = Settings::new(&matches);
+    let settings = Settings::new(&matches)?;
 
     wc(&inputs, &settings)
 }
@@ -212,6 +341,25 @@ pub fn uu_app<'a>() -> Command<'a> {
     If F is - then read names from standard input",
                 ),
         )
+        .arg(
+            Arg::new(options::FORMAT)
+                .long(options::FORMAT)
+                .takes_value(true)
+                .value_name("FORMAT")
+                .possible_values(["default", "json", "csv"])
+                .help("choose the output format: 'default' for space-padded columns, or 'json'/'csv' for scripting"),
+        )
+        .arg(
+            Arg::new(options::JOBS)
+                .long(options::JOBS)
+                .takes_value(true)
+                .value_name("N")
+                .help(
+                    "count files in parallel using N worker threads; with many file
+    operands (e.g. via --files0-from) this defaults to the available
+    parallelism once the file count passes a threshold",
+                ),
+        )
         .arg(
             Arg::new(options::LINES)
                 .short('l')
@@ -224,6 +372,14 @@ pub fn uu_app<'a>() -> Command<'a> {
                 .long(options::MAX_LINE_LENGTH)
                 .help("print the length of the longest line"),
         )
+        .arg(
+            Arg::new(options::TOTAL)
+                .long(options::TOTAL)
+                .takes_value(true)
+                .value_name("WHEN")
+                .possible_values(["auto", "always", "only", "never"])
+                .help("when to print a line with total counts"),
+        )
         .arg(
             Arg::new(options::WORDS)
                 .short('w')
@@ -389,13 +545,22 @@ fn word_count_from_input(input: &Input, settings: &Settings) -> CountResult {
                 (total, None) => CountResult::Success(total),
             }
         }
-        Input::Path(path) => match File::open(path) {
-            Err(error) => CountResult::Failure(error),
-            Ok(file) => match word_count_from_reader(file, settings) {
-                (total, Some(error)) => CountResult::Interrupted(total, error),
-                (total, None) => CountResult::Success(total),
-            },
-        },
+        Input::Path(path) => {
+            if let Some(mmap) = try_mmap(path) {
+                return match word_count_from_reader(&mmap[..], settings) {
+                    (total, Some(error)) => CountResult::Interrupted(total, error),
+                    (total, None) => CountResult::Success(total),
+                };
+            }
+
+            match File::open(path) {
+                Err(error) => CountResult::Failure(error),
+                Ok(file) => match word_count_from_reader(file, settings) {
+                    (total, Some(error)) => CountResult::Interrupted(total, error),
+                    (total, None) => CountResult::Success(total),
+                },
+            }
+        }
     }
 }
 
@@ -443,6 +608,86 @@ fn compute_number_width(inputs: &[Input], settings: &Settings) -> usize {
     max(minimum_width, total.to_string().len())
 }
 
+/// Decide how many worker threads to use for counting `path_count` path
+/// inputs, given the `--jobs` value the user asked for (if any).
+///
+/// An explicit `--jobs N` is always honored. Otherwise parallel counting
+/// only kicks in once `path_count` clears [`PARALLEL_FILE_THRESHOLD`],
+/// since for a handful of files the thread pool setup costs more than it
+/// saves.
+fn effective_jobs(requested: Option<usize>, path_count: usize) -> usize {
+    match requested {
+        Some(n) => n.max(1),
+        None if path_count > PARALLEL_FILE_THRESHOLD => thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1),
+        None => 1,
+    }
+}
+
+/// Count every input, returning one [`CountResult`] per input in the same
+/// order as `inputs`.
+///
+/// When `jobs` is greater than one, [`Input::Path`] entries are dispatched
+/// across at most `jobs` worker threads; [`Input::Stdin`] entries always run on the
+/// main thread since they share a single handle that can't be split across
+/// workers.
+fn compute_counts(inputs: &[Input], settings: &Settings, jobs: usize) -> Vec<CountResult> {
+    if jobs <= 1 {
+        return inputs
+            .iter()
+            .map(|input| word_count_from_input(input, settings))
+            .collect();
+    }
+
+    let mut results: Vec<Option<CountResult>> = inputs.iter().map(|_| None).collect();
+
+    let mut paths = Vec::new();
+    for (i, input) in inputs.iter().enumerate() {
+        match input {
+            Input::Stdin(_) => results[i] = Some(word_count_from_input(input, settings)),
+            Input::Path(path) => paths.push((i, path.clone())),
+        }
+    }
+
+    if !paths.is_empty() {
+        let settings = Arc::new(settings.clone());
+        let paths = Arc::new(paths);
+        let chunk_size = (paths.len() + jobs - 1) / jobs;
+        let (tx, rx) = mpsc::channel();
+
+        let handles: Vec<_> = (0..paths.len())
+            .step_by(chunk_size.max(1))
+            .map(|start| {
+                let end = (start + chunk_size.max(1)).min(paths.len());
+                let settings = Arc::clone(&settings);
+                let paths = Arc::clone(&paths);
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    for (i, path) in &paths[start..end] {
+                        let result =
+                            word_count_from_input(&Input::Path(path.clone()), &settings);
+                        tx.send((*i, result)).expect("receiver dropped");
+                    }
+                })
+            })
+            .collect();
+        drop(tx);
+
+        for (i, result) in rx {
+            results[i] = Some(result);
+        }
+        for handle in handles {
+            let _ = handle.join();
+        }
+    }
+
+    results
+        .into_iter()
+        .map(|result| result.expect("every input produces a result"))
+        .collect()
+}
+
 fn wc(inputs: &[Input], settings: &Settings) -> UResult<()> {
     let number_width = compute_number_width(inputs, settings);
 
@@ -450,8 +695,15 @@ fn wc(inputs: &[Input], settings: &Settings) -> UResult<()> {
 
     let num_inputs = inputs.len();
 
-    for input in inputs {
-        let word_count = match word_count_from_input(input, settings) {
+    let path_count = inputs
+        .iter()
+        .filter(|input| matches!(input, Input::Path(_)))
+        .count();
+    let jobs = effective_jobs(settings.jobs, path_count);
+    let counts = compute_counts(inputs, settings, jobs);
+
+    for (input, count_result) in inputs.iter().zip(counts) {
+        let word_count = match count_result {
             CountResult::Success(word_count) => word_count,
             CountResult::Interrupted(word_count, error) => {
                 show!(USimpleError::new(
@@ -470,23 +722,32 @@ fn wc(inputs: &[Input], settings: &Settings) -> UResult<()> {
         };
         total_word_count += word_count;
         let result = word_count.with_title(input.to_title());
-        if let Err(err) = print_stats(settings, &result, number_width) {
-            show!(USimpleError::new(
-                1,
-                format!(
-                    "failed to print result for {}: {}",
-                    result
-                        .title
-                        .unwrap_or_else(|| "<stdin>".as_ref())
-                        .maybe_quote(),
-                    err,
-                ),
-            ));
+        if settings.total_when.are_rows_visible() {
+            if let Err(err) = print_stats(settings, &result, number_width) {
+                show!(USimpleError::new(
+                    1,
+                    format!(
+                        "failed to print result for {}: {}",
+                        result
+                            .title
+                            .unwrap_or_else(|| "<stdin>".as_ref())
+                            .maybe_quote(),
+                        err,
+                    ),
+                ));
+            }
         }
     }
 
-    if num_inputs > 1 {
-        let total_result = total_word_count.with_title(Some("total".as_ref()));
+    if settings.total_when.is_total_row_visible(num_inputs) {
+        // GNU `wc --total=only` prints just the counts, with no "total"
+        // label, since it's the only row on output.
+        let title = if settings.total_when == TotalWhen::Only {
+            None
+        } else {
+            Some("total".as_ref())
+        };
+        let total_result = total_word_count.with_title(title);
         if let Err(err) = print_stats(settings, &total_result, number_width) {
             show!(USimpleError::new(
                 1,
@@ -500,10 +761,24 @@ fn wc(inputs: &[Input], settings: &Settings) -> UResult<()> {
     Ok(())
 }
 
+/// Print `result` in `settings.format`, dispatching to the matching
+/// emitter below.
 fn print_stats(
     settings: &Settings,
     result: &TitledWordCount,
     number_width: usize,
+) -> io::Result<()> {
+    match settings.format {
+        OutputFormat::Default => print_stats_default(settings, result, number_width),
+        OutputFormat::Json => print_stats_json(settings, result),
+        OutputFormat::Csv => print_stats_csv(settings, result),
+    }
+}
+
+fn print_stats_default(
+    settings: &Settings,
+    result: &TitledWordCount,
+    number_width: usize,
 ) -> io::Result<()> {
     let mut columns = Vec::new();
 
@@ -529,3 +804,236 @@ fn print_stats(
 
     writeln!(io::stdout().lock(), "{}", columns.join(" "))
 }
+
+/// Emit `result` as a single JSON object, one per call, so a multi-input
+/// run produces newline-delimited JSON that's trivial to stream-parse.
+fn print_stats_json(settings: &Settings, result: &TitledWordCount) -> io::Result<()> {
+    let mut fields = Vec::new();
+
+    if settings.show_lines {
+        fields.push(format!("\"lines\":{}", result.count.lines));
+    }
+    if settings.show_words {
+        fields.push(format!("\"words\":{}", result.count.words));
+    }
+    if settings.show_chars {
+        fields.push(format!("\"chars\":{}", result.count.chars));
+    }
+    if settings.show_bytes {
+        fields.push(format!("\"bytes\":{}", result.count.bytes));
+    }
+    if settings.show_max_line_length {
+        fields.push(format!(
+            "\"max_line_length\":{}",
+            result.count.max_line_length
+        ));
+    }
+    if let Some(title) = result.title {
+        fields.push(format!(
+            "\"title\":{}",
+            json_quote(&title.to_string_lossy())
+        ));
+    }
+
+    writeln!(io::stdout().lock(), "{{{}}}", fields.join(","))
+}
+
+/// Emit `result` as a single CSV row.
+fn print_stats_csv(settings: &Settings, result: &TitledWordCount) -> io::Result<()> {
+    let mut columns = Vec::new();
+
+    if settings.show_lines {
+        columns.push(result.count.lines.to_string());
+    }
+    if settings.show_words {
+        columns.push(result.count.words.to_string());
+    }
+    if settings.show_chars {
+        columns.push(result.count.chars.to_string());
+    }
+    if settings.show_bytes {
+        columns.push(result.count.bytes.to_string());
+    }
+    if settings.show_max_line_length {
+        columns.push(result.count.max_line_length.to_string());
+    }
+    if let Some(title) = result.title {
+        columns.push(csv_quote(&title.to_string_lossy()));
+    }
+
+    writeln!(io::stdout().lock(), "{}", columns.join(","))
+}
+
+/// Quote `s` as a JSON string literal.
+fn json_quote(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for ch in s.chars() {
+        match ch {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if (c as u32) < 0x20 => {
+                out.push_str(&format!("\\u{:04x}", c as u32));
+            }
+            _ => out.push(ch),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Quote `s` as a CSV field per RFC 4180, only when it needs it.
+fn csv_quote(s: &str) -> String {
+    if s.contains(',') || s.contains('"') || s.contains('\n') {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod format_tests {
+    use super::{csv_quote, json_quote, OutputFormat};
+
+    #[test]
+    fn output_format_parses_from_clap_possible_values() {
+        assert_eq!(OutputFormat::from("default"), OutputFormat::Default);
+        assert_eq!(OutputFormat::from("json"), OutputFormat::Json);
+        assert_eq!(OutputFormat::from("csv"), OutputFormat::Csv);
+    }
+
+    #[test]
+    fn json_quote_escapes_special_characters() {
+        assert_eq!(json_quote("plain"), "\"plain\"");
+        assert_eq!(json_quote("with \"quotes\""), "\"with \\\"quotes\\\"\"");
+        assert_eq!(json_quote("back\\slash"), "\"back\\\\slash\"");
+        assert_eq!(json_quote("line\nbreak"), "\"line\\nbreak\"");
+    }
+
+    #[test]
+    fn json_quote_escapes_the_full_control_char_set() {
+        assert_eq!(json_quote("tab\ttab"), "\"tab\\ttab\"");
+        assert_eq!(json_quote("cr\rcr"), "\"cr\\rcr\"");
+        assert_eq!(json_quote("\u{8}"), "\"\\b\"");
+        assert_eq!(json_quote("\u{c}"), "\"\\f\"");
+        assert_eq!(json_quote("\u{1}"), "\"\\u0001\"");
+        assert_eq!(json_quote("\u{1f}"), "\"\\u001f\"");
+    }
+
+    #[test]
+    fn csv_quote_only_quotes_fields_that_need_it() {
+        assert_eq!(csv_quote("plain"), "plain");
+        assert_eq!(csv_quote("a,b"), "\"a,b\"");
+        assert_eq!(csv_quote("has \"quote\""), "\"has \"\"quote\"\"\"");
+        assert_eq!(csv_quote("multi\nline"), "\"multi\nline\"");
+    }
+}
+
+#[cfg(test)]
+mod jobs_tests {
+    use super::{effective_jobs, parse_jobs, uu_app, PARALLEL_FILE_THRESHOLD};
+
+    #[test]
+    fn effective_jobs_honors_explicit_request_regardless_of_file_count() {
+        assert_eq!(effective_jobs(Some(3), 1), 3);
+        assert_eq!(effective_jobs(Some(1), 0), 1);
+    }
+
+    #[test]
+    fn effective_jobs_stays_serial_at_the_threshold() {
+        assert_eq!(effective_jobs(None, PARALLEL_FILE_THRESHOLD), 1);
+    }
+
+    #[test]
+    fn effective_jobs_parallelizes_above_the_threshold() {
+        let available = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1);
+        assert_eq!(effective_jobs(None, PARALLEL_FILE_THRESHOLD + 1), available);
+    }
+
+    #[test]
+    fn parse_jobs_accepts_a_positive_integer() {
+        let matches = uu_app()
+            .try_get_matches_from(["wc", "--jobs", "4"])
+            .unwrap();
+        assert_eq!(parse_jobs(&matches).unwrap(), Some(4));
+    }
+
+    #[test]
+    fn parse_jobs_defaults_to_none_when_absent() {
+        let matches = uu_app().try_get_matches_from(["wc"]).unwrap();
+        assert_eq!(parse_jobs(&matches).unwrap(), None);
+    }
+
+    #[test]
+    fn parse_jobs_rejects_zero() {
+        let matches = uu_app()
+            .try_get_matches_from(["wc", "--jobs", "0"])
+            .unwrap();
+        assert!(parse_jobs(&matches).is_err());
+    }
+
+    #[test]
+    fn parse_jobs_rejects_negative_numbers() {
+        // clap may reject "-2" itself for looking like an option, or hand
+        // it through as the value of --jobs; either way the end result
+        // must be an error, never a silently accepted job count.
+        match uu_app().try_get_matches_from(["wc", "--jobs", "-2"]) {
+            Err(_) => {}
+            Ok(matches) => assert!(parse_jobs(&matches).is_err()),
+        }
+    }
+
+    #[test]
+    fn parse_jobs_rejects_non_numeric_input() {
+        let matches = uu_app()
+            .try_get_matches_from(["wc", "--jobs", "abc"])
+            .unwrap();
+        assert!(parse_jobs(&matches).is_err());
+    }
+}
+
+#[cfg(test)]
+mod total_when_tests {
+    use super::TotalWhen;
+
+    #[test]
+    fn auto_matches_gnu_default_behavior() {
+        assert!(!TotalWhen::Auto.is_total_row_visible(1));
+        assert!(TotalWhen::Auto.is_total_row_visible(2));
+        assert!(TotalWhen::Auto.are_rows_visible());
+    }
+
+    #[test]
+    fn always_prints_total_even_for_one_input() {
+        assert!(TotalWhen::Always.is_total_row_visible(1));
+        assert!(TotalWhen::Always.are_rows_visible());
+    }
+
+    #[test]
+    fn never_suppresses_the_total_row() {
+        assert!(!TotalWhen::Never.is_total_row_visible(1));
+        assert!(!TotalWhen::Never.is_total_row_visible(2));
+        assert!(TotalWhen::Never.are_rows_visible());
+    }
+
+    #[test]
+    fn only_shows_the_total_row_and_hides_the_rest() {
+        assert!(TotalWhen::Only.is_total_row_visible(1));
+        assert!(!TotalWhen::Only.are_rows_visible());
+    }
+
+    #[test]
+    fn parses_from_clap_possible_values() {
+        assert_eq!(TotalWhen::from("auto"), TotalWhen::Auto);
+        assert_eq!(TotalWhen::from("always"), TotalWhen::Always);
+        assert_eq!(TotalWhen::from("only"), TotalWhen::Only);
+        assert_eq!(TotalWhen::from("never"), TotalWhen::Never);
+    }
+}
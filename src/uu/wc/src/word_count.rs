@@ -0,0 +1,54 @@
+//  * This file is part of the uutils coreutils package.
+//  *
+//  * (c) Boden Garman <bpgarman@gmail.com>
+//  *
+//  * For the full copyright and license information, please view the LICENSE
+//  * file that was distributed with this source code.
+
+use std::cmp::max;
+use std::ops::{Add, AddAssign};
+use std::path::Path;
+
+/// The counts associated with a single input.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct WordCount {
+    pub bytes: usize,
+    pub chars: usize,
+    pub lines: usize,
+    pub words: usize,
+    pub max_line_length: usize,
+}
+
+impl Add for WordCount {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            bytes: self.bytes + other.bytes,
+            chars: self.chars + other.chars,
+            lines: self.lines + other.lines,
+            words: self.words + other.words,
+            max_line_length: max(self.max_line_length, other.max_line_length),
+        }
+    }
+}
+
+impl AddAssign for WordCount {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl WordCount {
+    /// Attach the title that should be printed alongside this count.
+    pub fn with_title(self, title: Option<&Path>) -> TitledWordCount<'_> {
+        TitledWordCount { title, count: self }
+    }
+}
+
+/// A [`WordCount`] together with the title it should be printed under.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TitledWordCount<'a> {
+    pub title: Option<&'a Path>,
+    pub count: WordCount,
+}